@@ -0,0 +1,71 @@
+//! Device-to-host copies for GPU-resident input buffers.
+//!
+//! Gated behind the `cuda` feature so that backends which never see GPU
+//! inputs don't pay for a CUDA dependency.
+
+use crate::Error;
+use cudarc::driver::sys::{
+    cuCtxPopCurrent_v2, cuCtxPushCurrent_v2, cuDeviceGet, cuDevicePrimaryCtxRelease_v2,
+    cuDevicePrimaryCtxRetain, cuMemcpyDtoH_v2, CUcontext, CUdevice, CUdeviceptr,
+};
+use std::ptr;
+
+/// Copies `len` bytes from device memory at `ptr` (on `device_id`) into a
+/// freshly allocated host `Vec<u8>`.
+///
+/// The driver API operates on whatever context is current on the calling
+/// thread, not on whatever device `ptr` happens to live on, so this retains
+/// `device_id`'s primary context and pushes it as current for the duration
+/// of the copy (restoring/releasing it afterwards) rather than assuming the
+/// caller already has the right context current.
+pub(crate) fn copy_device_to_host(
+    ptr: *const u8,
+    len: usize,
+    device_id: i64,
+) -> Result<Vec<u8>, Error> {
+    let mut host = vec![0u8; len];
+
+    unsafe {
+        let mut device: CUdevice = 0;
+        check(cuDeviceGet(&mut device, device_id as i32), device_id)?;
+
+        let mut ctx: CUcontext = ptr::null_mut();
+        check(cuDevicePrimaryCtxRetain(&mut ctx, device), device_id)?;
+
+        let push_result = cuCtxPushCurrent_v2(ctx);
+        if push_result != 0 {
+            cuDevicePrimaryCtxRelease_v2(device);
+            return Err(cuda_error(push_result, device_id));
+        }
+
+        let copy_result = cuMemcpyDtoH_v2(
+            host.as_mut_ptr() as *mut std::ffi::c_void,
+            ptr as CUdeviceptr,
+            len,
+        );
+
+        let mut previous_ctx: CUcontext = ptr::null_mut();
+        cuCtxPopCurrent_v2(&mut previous_ctx);
+        cuDevicePrimaryCtxRelease_v2(device);
+
+        if copy_result != 0 {
+            return Err(cuda_error(copy_result, device_id));
+        }
+    }
+
+    Ok(host)
+}
+
+fn check(result: i32, device_id: i64) -> Result<(), Error> {
+    if result != 0 {
+        Err(cuda_error(result, device_id))
+    } else {
+        Ok(())
+    }
+}
+
+fn cuda_error(code: i32, device_id: i64) -> Error {
+    Error::from(format!(
+        "CUDA driver call failed for device {device_id} with code {code}"
+    ))
+}
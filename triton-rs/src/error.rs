@@ -0,0 +1,110 @@
+//! Error types returned by this crate.
+
+use std::ffi::CStr;
+use thiserror::Error as ThisError;
+
+/// Errors returned by this crate.
+#[derive(Debug, ThisError)]
+pub enum Error {
+    /// An error returned by the Triton server itself, carrying the
+    /// `TRITONSERVER_errorcode` and the associated error message.
+    #[error("Triton error ({code:?}): {message}")]
+    Triton {
+        code: TritonErrorCode,
+        message: String,
+    },
+
+    /// A one-off error message, for failures that don't originate from a
+    /// `TRITONSERVER_Error`.
+    #[error("{0}")]
+    Message(String),
+
+    #[error(transparent)]
+    Utf8(#[from] std::str::Utf8Error),
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[cfg(feature = "json")]
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+}
+
+impl From<&str> for Error {
+    fn from(message: &str) -> Self {
+        Error::Message(message.to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(message: String) -> Self {
+        Error::Message(message)
+    }
+}
+
+/// Mirrors the `TRITONSERVER_errorcode_enum` values so callers can match on
+/// the kind of failure Triton reported instead of string-matching messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TritonErrorCode {
+    Unknown,
+    Internal,
+    NotFound,
+    InvalidArg,
+    Unavailable,
+    Unsupported,
+    AlreadyExists,
+}
+
+impl From<triton_sys::TRITONSERVER_Error_Code> for TritonErrorCode {
+    fn from(code: triton_sys::TRITONSERVER_Error_Code) -> Self {
+        match code {
+            triton_sys::TRITONSERVER_errorcode_enum_TRITONSERVER_ERROR_INTERNAL => {
+                TritonErrorCode::Internal
+            }
+            triton_sys::TRITONSERVER_errorcode_enum_TRITONSERVER_ERROR_NOT_FOUND => {
+                TritonErrorCode::NotFound
+            }
+            triton_sys::TRITONSERVER_errorcode_enum_TRITONSERVER_ERROR_INVALID_ARG => {
+                TritonErrorCode::InvalidArg
+            }
+            triton_sys::TRITONSERVER_errorcode_enum_TRITONSERVER_ERROR_UNAVAILABLE => {
+                TritonErrorCode::Unavailable
+            }
+            triton_sys::TRITONSERVER_errorcode_enum_TRITONSERVER_ERROR_UNSUPPORTED => {
+                TritonErrorCode::Unsupported
+            }
+            triton_sys::TRITONSERVER_errorcode_enum_TRITONSERVER_ERROR_ALREADY_EXISTS => {
+                TritonErrorCode::AlreadyExists
+            }
+            _ => TritonErrorCode::Unknown,
+        }
+    }
+}
+
+/// Checks a `TRITONSERVER_Error*` returned from an FFI call, converting a
+/// non-null error into an [`Error::Triton`] carrying its code and message.
+pub(crate) fn check_err(err: *mut triton_sys::TRITONSERVER_Error) -> Result<(), Error> {
+    if err.is_null() {
+        return Ok(());
+    }
+
+    let code = unsafe { triton_sys::TRITONSERVER_ErrorCode(err) };
+    let message = unsafe {
+        let message = triton_sys::TRITONSERVER_ErrorMessage(err);
+        if message.is_null() {
+            String::new()
+        } else {
+            CStr::from_ptr(message).to_string_lossy().to_string()
+        }
+    };
+
+    // Triton hands ownership of `err` to the code that received it, so it is
+    // deleted here once its code and message have been read out; callers of
+    // `check_err` must not delete it again.
+    unsafe { triton_sys::TRITONSERVER_ErrorDelete(err) };
+
+    Err(Error::Triton {
+        code: TritonErrorCode::from(code),
+        message,
+    })
+}
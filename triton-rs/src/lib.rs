@@ -1,32 +1,27 @@
 mod backend;
+#[cfg(feature = "cuda")]
+mod cuda;
+mod error;
 #[cfg(feature = "tracing")]
 mod log;
+mod memory;
 mod model;
 mod request;
 mod response;
+mod tensor;
 
 pub use backend::Backend;
+pub use error::{Error, TritonErrorCode};
 #[cfg(feature = "tracing")]
 pub use log::TritonLogger;
+pub use memory::{BufferRef, MemoryType};
 pub use model::Model;
-pub use request::Request;
-pub use response::{Output, Response};
+pub use request::{DataType, Input, InputProperties, Request};
+pub use response::{Output, Response, ResponseFactory};
+pub use tensor::{Endianness, Tensor, TensorElement};
 pub use triton_sys as sys;
 
-pub type Error = Box<dyn std::error::Error>;
-
-pub(crate) fn check_err(err: *mut triton_sys::TRITONSERVER_Error) -> Result<(), Error> {
-    if !err.is_null() {
-        let code = unsafe { triton_sys::TRITONSERVER_ErrorCode(err) };
-        Err(format!(
-            "TRITONBACKEND_ModelInstanceModel returned error code {}",
-            code
-        )
-        .into())
-    } else {
-        Ok(())
-    }
-}
+pub(crate) use error::check_err;
 
 pub fn decode_string(data: &[u8]) -> Result<Vec<String>, Error> {
     let mut strings = vec![];
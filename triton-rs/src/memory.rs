@@ -0,0 +1,134 @@
+//! Memory-space-aware access to a single Triton buffer segment.
+
+use crate::Error;
+use std::slice;
+
+/// The memory space a Triton buffer lives in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemoryType {
+    /// Regular host memory.
+    Cpu,
+    /// Pinned (page-locked) host memory.
+    CpuPinned,
+    /// Device memory on a CUDA-capable GPU.
+    Gpu,
+}
+
+impl From<triton_sys::TRITONSERVER_MemoryType> for MemoryType {
+    fn from(memory_type: triton_sys::TRITONSERVER_MemoryType) -> Self {
+        match memory_type {
+            triton_sys::TRITONSERVER_memorytype_enum_TRITONSERVER_MEMORY_CPU_PINNED => {
+                MemoryType::CpuPinned
+            }
+            triton_sys::TRITONSERVER_memorytype_enum_TRITONSERVER_MEMORY_GPU => MemoryType::Gpu,
+            _ => MemoryType::Cpu,
+        }
+    }
+}
+
+impl From<MemoryType> for triton_sys::TRITONSERVER_MemoryType {
+    fn from(memory_type: MemoryType) -> Self {
+        match memory_type {
+            MemoryType::Cpu => triton_sys::TRITONSERVER_memorytype_enum_TRITONSERVER_MEMORY_CPU,
+            MemoryType::CpuPinned => {
+                triton_sys::TRITONSERVER_memorytype_enum_TRITONSERVER_MEMORY_CPU_PINNED
+            }
+            MemoryType::Gpu => triton_sys::TRITONSERVER_memorytype_enum_TRITONSERVER_MEMORY_GPU,
+        }
+    }
+}
+
+/// A reference to one buffer segment backing a Triton input, together with
+/// the memory space Triton placed it in.
+///
+/// Unlike a plain `&[u8]`, a `BufferRef` can describe a GPU-resident buffer:
+/// [`BufferRef::as_host_slice`] only succeeds for CPU/CPU-pinned memory, and
+/// [`BufferRef::device_id`] and the raw pointer are available regardless of
+/// memory type for backends that want to hand the buffer directly to CUDA
+/// APIs (see the `cuda` feature).
+#[derive(Debug)]
+pub struct BufferRef<'a> {
+    ptr: *const u8,
+    len: usize,
+    memory_type: MemoryType,
+    device_id: i64,
+    _marker: std::marker::PhantomData<&'a [u8]>,
+}
+
+impl<'a> BufferRef<'a> {
+    pub(crate) fn new(ptr: *const u8, len: usize, memory_type: MemoryType, device_id: i64) -> Self {
+        Self {
+            ptr,
+            len,
+            memory_type,
+            device_id,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// The memory space this buffer lives in.
+    pub fn memory_type(&self) -> MemoryType {
+        self.memory_type
+    }
+
+    /// The device this buffer lives on, meaningful when [`Self::memory_type`]
+    /// is [`MemoryType::Gpu`].
+    pub fn device_id(&self) -> i64 {
+        self.device_id
+    }
+
+    /// The buffer's length in bytes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The raw pointer backing this buffer, along with its length.
+    ///
+    /// For [`MemoryType::Gpu`] buffers this is a device pointer and must
+    /// not be dereferenced from the host; it's exposed for backends that
+    /// hand buffers directly to CUDA APIs (e.g. `cust`/`cudarc`).
+    pub fn as_raw_parts(&self) -> (*const u8, usize) {
+        (self.ptr, self.len)
+    }
+
+    /// Returns the buffer as a host-accessible byte slice, if it lives in
+    /// CPU or CPU-pinned memory.
+    pub fn as_host_slice(&self) -> Option<&'a [u8]> {
+        match self.memory_type {
+            // `slice::from_raw_parts` requires a non-null, aligned pointer
+            // even for a zero-length slice, and Triton can legitimately hand
+            // back a null pointer for an empty buffer, so avoid touching
+            // `self.ptr` in that case.
+            MemoryType::Cpu | MemoryType::CpuPinned if self.len == 0 => Some(&[]),
+            MemoryType::Cpu | MemoryType::CpuPinned => {
+                Some(unsafe { slice::from_raw_parts(self.ptr, self.len) })
+            }
+            MemoryType::Gpu => None,
+        }
+    }
+
+    /// Copies the buffer into host memory, issuing a device-to-host copy if
+    /// it is GPU-resident. Requires the `cuda` feature for GPU buffers.
+    pub fn to_host_vec(&self) -> Result<Vec<u8>, Error> {
+        match self.as_host_slice() {
+            Some(slice) => Ok(slice.to_vec()),
+            None => {
+                #[cfg(feature = "cuda")]
+                {
+                    crate::cuda::copy_device_to_host(self.ptr, self.len, self.device_id)
+                }
+                #[cfg(not(feature = "cuda"))]
+                {
+                    Err(Error::from(
+                        "GPU memory requires the `cuda` feature to be enabled",
+                    ))
+                }
+            }
+        }
+    }
+}
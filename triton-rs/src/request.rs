@@ -1,3 +1,6 @@
+use crate::memory::{BufferRef, MemoryType};
+use crate::response::{Response, ResponseFactory};
+use crate::tensor::Tensor;
 use crate::{check_err, decode_string, Error};
 use libc::c_void;
 use std::borrow::Cow;
@@ -31,6 +34,44 @@ impl Request {
 
         Ok(Input::from_ptr(input))
     }
+
+    /// Creates a new [`Response`] for this request.
+    ///
+    /// Corresponds to `TRITONBACKEND_ResponseNew`.
+    pub fn new_response(&self) -> Result<Response, Error> {
+        Response::new(self)
+    }
+
+    /// Creates a [`ResponseFactory`] for this request, for backends running
+    /// in decoupled (streaming) mode that need to produce zero, one, or many
+    /// responses over time rather than exactly one response before
+    /// `model_instance_execute` returns.
+    ///
+    /// Corresponds to `TRITONBACKEND_ResponseFactoryNew`.
+    pub fn response_factory(&self) -> Result<ResponseFactory, Error> {
+        ResponseFactory::new(self)
+    }
+}
+
+/// Validates and wraps the raw out-parameters shared by
+/// `TRITONBACKEND_InputBuffer` and `TRITONBACKEND_InputBufferForHostPolicy`
+/// into a [`BufferRef`].
+fn buffer_ref_from_raw<'a>(
+    buffer: *const c_void,
+    buffer_byte_size: u64,
+    memory_type: triton_sys::TRITONSERVER_MemoryType,
+    memory_type_id: i64,
+) -> BufferRef<'a> {
+    debug_assert!(buffer.is_aligned());
+    // A null pointer is only legitimate for an empty buffer; `BufferRef`
+    // itself takes care not to dereference it in that case.
+    debug_assert!(!buffer.is_null() || buffer_byte_size == 0);
+    BufferRef::new(
+        buffer as *const u8,
+        buffer_byte_size as usize,
+        MemoryType::from(memory_type),
+        memory_type_id,
+    )
 }
 
 pub struct Input {
@@ -43,7 +84,7 @@ impl Input {
 
     /// Gets a reference to the buffer associated with the input. Note the buffer index must
     /// be less than the buffer count (
-    fn raw_buffer(&self, buffer_index: u32) -> Result<&[u8], Error> {
+    fn raw_buffer(&self, buffer_index: u32) -> Result<BufferRef, Error> {
         let mut buffer: *const c_void = ptr::null_mut();
         let mut memory_type = triton_sys::TRITONSERVER_memorytype_enum_TRITONSERVER_MEMORY_CPU;
         let mut memory_type_id = 0;
@@ -58,36 +99,92 @@ impl Input {
                 &mut memory_type_id,
             )
         })?;
-        match memory_type {
-            triton_sys::TRITONSERVER_memorytype_enum_TRITONSERVER_MEMORY_CPU
-            | triton_sys::TRITONSERVER_memorytype_enum_TRITONSERVER_MEMORY_CPU_PINNED => {
-                debug_assert!(buffer.is_aligned());
-                debug_assert!(!buffer.is_null());
-                let buffer = buffer as *const u8;
-                Ok(unsafe { slice::from_raw_parts(buffer, buffer_byte_size as usize) })
-            }
-            _ => Err(Error::from("GPU memory is unsupported")),
-        }
+        Ok(buffer_ref_from_raw(
+            buffer,
+            buffer_byte_size,
+            memory_type,
+            memory_type_id,
+        ))
     }
 
+    /// Reads this input's buffer into host memory.
+    ///
+    /// A single buffer already in CPU (or CPU-pinned) memory is borrowed;
+    /// an input split across several buffers is concatenated into an owned
+    /// copy. GPU-resident buffers are copied to the host, which requires the
+    /// `cuda` feature. Backends that want to avoid that copy, or that need
+    /// to keep a GPU buffer on-device, should use [`Input::raw_buffers`]
+    /// instead.
     pub fn buffer(&self) -> Result<Cow<[u8]>, Error> {
         let properties = self.properties()?;
         match properties.buffer_count {
             1 => {
-                let retval = self.raw_buffer(0)?;
-                Ok(Cow::Borrowed(retval))
+                let buffer = self.raw_buffer(0)?;
+                match buffer.as_host_slice() {
+                    Some(slice) => Ok(Cow::Borrowed(slice)),
+                    None => Ok(Cow::Owned(buffer.to_host_vec()?)),
+                }
             }
             _ => {
                 let mut retval = Vec::with_capacity(properties.byte_size as usize);
                 for buf_idx in 0..properties.buffer_count {
                     let buffer = self.raw_buffer(buf_idx)?;
-                    retval.extend_from_slice(buffer);
+                    retval.extend_from_slice(&buffer.to_host_vec()?);
                 }
                 Ok(Cow::Owned(retval))
             }
         }
     }
 
+    /// Returns every buffer segment backing this input without copying, so
+    /// that GPU-resident inputs can be handled without an implicit host
+    /// round-trip (see [`BufferRef`]).
+    pub fn raw_buffers(&self) -> Result<Vec<BufferRef>, Error> {
+        let properties = self.properties()?;
+        (0..properties.buffer_count)
+            .map(|buf_idx| self.raw_buffer(buf_idx))
+            .collect()
+    }
+
+    /// Asks Triton to place this input's buffers according to `host_policy_name`
+    /// (e.g. to pull them onto a specific GPU) rather than assuming CPU, then
+    /// returns the resulting buffer segments.
+    ///
+    /// Corresponds to `TRITONBACKEND_InputBufferForHostPolicy`. The named
+    /// host policy must be configured for the model; see the Triton backend
+    /// API docs for `host_policy`.
+    pub fn preferred_memory(&self, host_policy_name: &str) -> Result<Vec<BufferRef>, Error> {
+        let host_policy_name = CString::new(host_policy_name).expect("CString::new failed");
+        let properties = self.properties()?;
+
+        (0..properties.buffer_count)
+            .map(|buffer_index| {
+                let mut buffer: *const c_void = ptr::null_mut();
+                let mut memory_type =
+                    triton_sys::TRITONSERVER_memorytype_enum_TRITONSERVER_MEMORY_CPU;
+                let mut memory_type_id = 0;
+                let mut buffer_byte_size = 0;
+                check_err(unsafe {
+                    triton_sys::TRITONBACKEND_InputBufferForHostPolicy(
+                        self.ptr,
+                        host_policy_name.as_ptr(),
+                        buffer_index,
+                        &mut buffer,
+                        &mut buffer_byte_size,
+                        &mut memory_type,
+                        &mut memory_type_id,
+                    )
+                })?;
+                Ok(buffer_ref_from_raw(
+                    buffer,
+                    buffer_byte_size,
+                    memory_type,
+                    memory_type_id,
+                ))
+            })
+            .collect()
+    }
+
     pub fn as_string(&self) -> Result<String, Error> {
         let buffer = self.buffer()?;
 
@@ -104,9 +201,24 @@ impl Input {
         Ok(u64::from_le_bytes(bytes))
     }
 
+    /// Returns a typed, shaped view over this input's buffer.
+    ///
+    /// The returned [`Tensor`] knows its [`DataType`] and shape, and can
+    /// decode its elements with [`Tensor::as_slice`] (or the `f16`/`bf16`/
+    /// string accessors) instead of callers hand-rolling byte math.
+    pub fn as_tensor(&self) -> Result<Tensor, Error> {
+        let properties = self.properties()?;
+        let datatype = DataType::try_from(properties.datatype)?;
+        let shape = properties.shape.to_vec();
+        let buffer = self.buffer()?;
+
+        Ok(Tensor::new(buffer, datatype, shape))
+    }
+
     pub fn properties(&self) -> Result<InputProperties, Error> {
         let mut name = ptr::null();
         let mut datatype = 0u32;
+        let mut shape: *const i64 = ptr::null();
         let mut dims_count = 0u32;
         let mut byte_size = 0u64;
         let mut buffer_count = 0u32;
@@ -116,7 +228,7 @@ impl Input {
                 self.ptr,
                 &mut name,
                 &mut datatype,
-                ptr::null_mut(),
+                &mut shape,
                 &mut dims_count,
                 &mut byte_size,
                 &mut buffer_count,
@@ -124,10 +236,25 @@ impl Input {
         })?;
 
         let name = unsafe { CStr::from_ptr(name) };
+        // `slice::from_raw_parts` requires a non-null, aligned pointer even
+        // for a zero-length slice, and Triton isn't guaranteed to hand back
+        // a non-null `shape` for a 0-dim (scalar) input, so special-case it
+        // rather than dereferencing a potentially null pointer.
+        let shape: &[i64] = if dims_count == 0 {
+            &[]
+        } else {
+            debug_assert!(!shape.is_null());
+            debug_assert!(shape.is_aligned());
+            // Safety: `shape` is owned by the request backing this `Input`,
+            // and is valid for `dims_count` elements for as long as the
+            // request is, which outlives the borrow returned here.
+            unsafe { slice::from_raw_parts(shape, dims_count as usize) }
+        };
 
         Ok(InputProperties {
             name,
             datatype,
+            shape,
             dims_count,
             byte_size,
             buffer_count,
@@ -139,7 +266,7 @@ impl Input {
 pub struct InputProperties<'a> {
     pub name: &'a CStr,
     pub datatype: u32,
-    // pub shape: &'a [i64],
+    pub shape: &'a [i64],
     pub dims_count: u32,
     pub byte_size: u64,
     pub buffer_count: u32,
@@ -1,7 +1,9 @@
 //! Triton inference response handling.
-use crate::{check_err, Error};
+use crate::tensor::{Endianness, TensorElement};
+use crate::{check_err, DataType, Error};
 use libc::c_void;
 use std::ffi::CString;
+use std::mem::size_of;
 use std::ptr;
 use std::slice;
 #[cfg(feature = "tracing")]
@@ -29,13 +31,18 @@ impl Response {
         })
     }
 
-    /// Creates a new output tensor in the response.
+    /// Adds a new output tensor to the response.
     ///
     /// # Arguments
     /// * `name` - Name of the output tensor
-    /// * `datatype` - The data type of the tensor (as a Triton type code)
+    /// * `datatype` - The data type of the tensor
     /// * `shape` - The shape of the output tensor
-    pub fn output(&mut self, name: &str, datatype: u32, shape: &[i64]) -> Result<Output, Error> {
+    pub fn add_output(
+        &mut self,
+        name: &str,
+        datatype: DataType,
+        shape: &[i64],
+    ) -> Result<Output, Error> {
         let mut output: *mut triton_sys::TRITONBACKEND_Output = ptr::null_mut();
         let name = CString::new(name).expect("CString::new failed");
 
@@ -44,7 +51,7 @@ impl Response {
                 self.ptr,
                 &mut output,
                 name.as_ptr(),
-                datatype,
+                datatype.into(),
                 shape.as_ptr(),
                 shape.len() as u32,
             )
@@ -53,12 +60,24 @@ impl Response {
         Ok(Output { ptr: output })
     }
 
-    /// Sends the response back to the client.
+    /// Sends the response back to the client without marking it as the final
+    /// response for the request. More responses may follow for the same
+    /// request (e.g. when streaming tokens one at a time).
+    pub fn send(self) -> Result<(), Error> {
+        self.send_with_flags(0)
+    }
+
+    /// Sends the response back to the client and marks it as the final
+    /// response for the request.
     ///
     /// This consumes the response object and finalizes the inference response.
-    pub fn send(mut self) -> Result<(), Error> {
+    pub fn send_final(self) -> Result<(), Error> {
         let send_flags =
             triton_sys::tritonserver_responsecompleteflag_enum_TRITONSERVER_RESPONSE_COMPLETE_FINAL;
+        self.send_with_flags(send_flags)
+    }
+
+    fn send_with_flags(mut self, send_flags: u32) -> Result<(), Error> {
         self.sent = true;
         let err = ptr::null_mut();
         check_err(unsafe { triton_sys::TRITONBACKEND_ResponseSend(self.ptr, send_flags, err) })
@@ -104,6 +123,111 @@ impl Output {
             )
         })?;
 
-        Ok(unsafe { slice::from_raw_parts_mut(buffer as *mut u8, size) })
+        match memory_type {
+            triton_sys::TRITONSERVER_memorytype_enum_TRITONSERVER_MEMORY_CPU
+            | triton_sys::TRITONSERVER_memorytype_enum_TRITONSERVER_MEMORY_CPU_PINNED => {
+                Ok(unsafe { slice::from_raw_parts_mut(buffer as *mut u8, size) })
+            }
+            _ => Err(Error::from(
+                "Triton allocated a non-CPU output buffer, which Output::buffer cannot write to",
+            )),
+        }
+    }
+
+    /// Allocates an output buffer of `data.len()` bytes and copies `data` into it.
+    pub fn write(&mut self, data: &[u8]) -> Result<(), Error> {
+        let buffer = self.buffer(data.len())?;
+        debug_assert_eq!(buffer.len(), data.len());
+        buffer.copy_from_slice(data);
+        Ok(())
+    }
+
+    /// Encodes `data` as bytes using `endianness` and writes them as the
+    /// output buffer.
+    pub fn write_slice_with<T: TensorElement>(
+        &mut self,
+        data: &[T],
+        endianness: Endianness,
+    ) -> Result<(), Error> {
+        let mut bytes = Vec::with_capacity(data.len() * size_of::<T>());
+        for element in data {
+            bytes.extend_from_slice(&element.to_bytes(endianness));
+        }
+        self.write(&bytes)
+    }
+
+    /// Encodes `data` as little-endian bytes (the default Triton uses on the
+    /// wire) and writes them as the output buffer.
+    pub fn write_slice<T: TensorElement>(&mut self, data: &[T]) -> Result<(), Error> {
+        self.write_slice_with(data, Endianness::Little)
+    }
+}
+
+/// A factory that can produce zero, one, or many [`Response`]s for a single
+/// request, for use in Triton's decoupled (streaming) execution mode, e.g.
+/// emitting tokens one at a time as an LLM generates them.
+///
+/// Obtained via [`Request::response_factory`](super::Request::response_factory).
+/// Unlike [`Response`], `ResponseFactory` doesn't borrow the request it was
+/// created from: Triton reference-counts the factory independently once
+/// created, which is what allows it to be moved into a worker thread and
+/// used to keep emitting responses after `model_instance_execute` returns
+/// and the request itself has been released.
+pub struct ResponseFactory {
+    ptr: *mut triton_sys::TRITONBACKEND_ResponseFactory,
+}
+
+// Safety: Triton's decoupled API is explicitly designed for the factory to
+// outlive the thread that created it and to be driven from another thread;
+// all of the `TRITONBACKEND_ResponseFactory*` functions are safe to call
+// concurrently with the request's own completion.
+unsafe impl Send for ResponseFactory {}
+
+impl ResponseFactory {
+    pub(crate) fn new(request: &super::Request) -> Result<Self, Error> {
+        let mut ptr: *mut triton_sys::TRITONBACKEND_ResponseFactory = ptr::null_mut();
+        check_err(unsafe {
+            triton_sys::TRITONBACKEND_ResponseFactoryNew(&mut ptr, request.as_ptr())
+        })?;
+        Ok(Self { ptr })
+    }
+
+    /// Creates a new [`Response`] from this factory.
+    ///
+    /// Corresponds to `TRITONBACKEND_ResponseNewFromFactory`.
+    pub fn new_response(&self) -> Result<Response, Error> {
+        let mut response: *mut triton_sys::TRITONBACKEND_Response = ptr::null_mut();
+        check_err(unsafe {
+            triton_sys::TRITONBACKEND_ResponseNewFromFactory(self.ptr, &mut response)
+        })?;
+        Ok(Response {
+            ptr: response,
+            sent: false,
+        })
+    }
+
+    /// Closes the stream with no further output, once every intermediate
+    /// [`Response`] produced by this factory has been sent with
+    /// [`Response::send`].
+    ///
+    /// Corresponds to `TRITONBACKEND_ResponseFactorySendFlags` with
+    /// `TRITONSERVER_RESPONSE_COMPLETE_FINAL`.
+    pub fn send_final(&self) -> Result<(), Error> {
+        let send_flags =
+            triton_sys::tritonserver_responsecompleteflag_enum_TRITONSERVER_RESPONSE_COMPLETE_FINAL;
+        check_err(unsafe {
+            triton_sys::TRITONBACKEND_ResponseFactorySendFlags(self.ptr, send_flags)
+        })
+    }
+}
+
+impl Drop for ResponseFactory {
+    fn drop(&mut self) {
+        let _result =
+            unsafe { check_err(triton_sys::TRITONBACKEND_ResponseFactoryDelete(self.ptr)) };
+        #[cfg(feature = "tracing")]
+        if let Err(error) = _result {
+            error!(error, "Failed to delete response factory");
+        }
     }
 }
@@ -0,0 +1,242 @@
+//! Typed, shaped views over an [`Input`](crate::Input)'s raw byte buffer.
+
+use crate::{decode_string, DataType, Error};
+use half::{bf16, f16};
+use std::borrow::Cow;
+use std::mem::size_of;
+
+/// A typed view over the bytes backing a Triton input tensor.
+///
+/// A `Tensor` pairs the raw buffer returned by [`Input::buffer`](crate::Input::buffer)
+/// with the [`DataType`] and shape reported by Triton, so callers can read it
+/// as a properly typed slice instead of hand-rolling byte math.
+pub struct Tensor<'a> {
+    data: Cow<'a, [u8]>,
+    datatype: DataType,
+    shape: Vec<i64>,
+}
+
+impl<'a> Tensor<'a> {
+    pub(crate) fn new(data: Cow<'a, [u8]>, datatype: DataType, shape: Vec<i64>) -> Self {
+        Self {
+            data,
+            datatype,
+            shape,
+        }
+    }
+
+    /// The tensor's datatype, as reported by Triton.
+    pub fn datatype(&self) -> DataType {
+        self.datatype
+    }
+
+    /// The tensor's shape, as reported by Triton.
+    pub fn shape(&self) -> &[i64] {
+        &self.shape
+    }
+
+    /// The number of elements described by [`Tensor::shape`].
+    pub fn element_count(&self) -> usize {
+        self.shape.iter().product::<i64>() as usize
+    }
+
+    /// Decodes the tensor into an owned `Vec<T>`, reading each element with
+    /// the given `endianness`.
+    ///
+    /// Returns an error if `T::DATA_TYPE` doesn't match this tensor's
+    /// [`DataType`], or if the buffer's byte size doesn't match
+    /// `shape.product() * size_of::<T>()`.
+    pub fn as_slice_with<T: TensorElement>(&self, endianness: Endianness) -> Result<Vec<T>, Error> {
+        self.check_type(T::DATA_TYPE)?;
+        self.check_byte_size(size_of::<T>())?;
+
+        Ok(self
+            .data
+            .chunks_exact(size_of::<T>())
+            .map(|chunk| T::from_bytes(chunk, endianness))
+            .collect())
+    }
+
+    /// Decodes the tensor into an owned `Vec<T>`, assuming little-endian byte
+    /// order (the default for tensors Triton carries on the wire).
+    pub fn as_slice<T: TensorElement>(&self) -> Result<Vec<T>, Error> {
+        self.as_slice_with(Endianness::Little)
+    }
+
+    /// Interprets the tensor as `f16` elements.
+    pub fn as_f16(&self, endianness: Endianness) -> Result<Vec<f16>, Error> {
+        self.check_type(DataType::Fp16)?;
+        self.check_byte_size(size_of::<u16>())?;
+
+        Ok(self
+            .data
+            .chunks_exact(2)
+            .map(|chunk| f16::from_bits(read_u16(chunk, endianness)))
+            .collect())
+    }
+
+    /// Interprets the tensor as `bf16` elements.
+    pub fn as_bf16(&self, endianness: Endianness) -> Result<Vec<bf16>, Error> {
+        self.check_type(DataType::Bf16)?;
+        self.check_byte_size(size_of::<u16>())?;
+
+        Ok(self
+            .data
+            .chunks_exact(2)
+            .map(|chunk| bf16::from_bits(read_u16(chunk, endianness)))
+            .collect())
+    }
+
+    /// Decodes every element of a `Bytes`/string tensor, rather than only the
+    /// first (as [`Input::as_string`](crate::Input::as_string) does).
+    pub fn as_strings(&self) -> Result<Vec<String>, Error> {
+        self.check_type(DataType::Bytes)?;
+        decode_string(&self.data)
+    }
+
+    fn check_type(&self, expected: DataType) -> Result<(), Error> {
+        if self.datatype != expected {
+            return Err(Error::from(format!(
+                "tensor datatype mismatch: expected {:?}, got {:?}",
+                expected, self.datatype
+            )));
+        }
+        Ok(())
+    }
+
+    fn check_byte_size(&self, element_size: usize) -> Result<(), Error> {
+        let expected = self.element_count() * element_size;
+        if self.data.len() != expected {
+            return Err(Error::from(format!(
+                "tensor byte size mismatch: shape {:?} implies {} bytes, buffer has {}",
+                self.shape,
+                expected,
+                self.data.len()
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Byte order used to decode numeric tensor elements.
+///
+/// Triton carries tensors on the wire in little-endian order, which is the
+/// default throughout this crate; `Big` is provided for hosts that need it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Endianness {
+    #[default]
+    Little,
+    Big,
+}
+
+fn read_u16(bytes: &[u8], endianness: Endianness) -> u16 {
+    let array = [bytes[0], bytes[1]];
+    match endianness {
+        Endianness::Little => u16::from_le_bytes(array),
+        Endianness::Big => u16::from_be_bytes(array),
+    }
+}
+
+/// A numeric type that can be decoded from the bytes of a Triton tensor.
+///
+/// Implemented for the primitive numeric types that map 1:1 onto a Triton
+/// [`DataType`]; `f16`/`bf16` are handled separately via
+/// [`Tensor::as_f16`]/[`Tensor::as_bf16`] since they aren't native Rust types.
+pub trait TensorElement: Sized {
+    /// The [`DataType`] this Rust type corresponds to on the wire.
+    const DATA_TYPE: DataType;
+
+    fn from_bytes(bytes: &[u8], endianness: Endianness) -> Self;
+
+    /// Encodes this element as bytes in the given byte order, for writing
+    /// into a Triton output buffer.
+    fn to_bytes(&self, endianness: Endianness) -> Vec<u8>;
+}
+
+macro_rules! impl_tensor_element {
+    ($ty:ty, $data_type:expr) => {
+        impl TensorElement for $ty {
+            const DATA_TYPE: DataType = $data_type;
+
+            fn from_bytes(bytes: &[u8], endianness: Endianness) -> Self {
+                let mut array = [0u8; size_of::<$ty>()];
+                array.copy_from_slice(bytes);
+                match endianness {
+                    Endianness::Little => <$ty>::from_le_bytes(array),
+                    Endianness::Big => <$ty>::from_be_bytes(array),
+                }
+            }
+
+            fn to_bytes(&self, endianness: Endianness) -> Vec<u8> {
+                match endianness {
+                    Endianness::Little => self.to_le_bytes().to_vec(),
+                    Endianness::Big => self.to_be_bytes().to_vec(),
+                }
+            }
+        }
+    };
+}
+
+impl_tensor_element!(u8, DataType::UInt8);
+impl_tensor_element!(u16, DataType::UInt16);
+impl_tensor_element!(u32, DataType::UInt32);
+impl_tensor_element!(u64, DataType::UInt64);
+impl_tensor_element!(i8, DataType::Int8);
+impl_tensor_element!(i16, DataType::Int16);
+impl_tensor_element!(i32, DataType::Int32);
+impl_tensor_element!(i64, DataType::Int64);
+impl_tensor_element!(f32, DataType::Fp32);
+impl_tensor_element!(f64, DataType::Fp64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tensor(data: Vec<u8>, datatype: DataType, shape: Vec<i64>) -> Tensor<'static> {
+        Tensor::new(Cow::Owned(data), datatype, shape)
+    }
+
+    #[test]
+    fn as_slice_rejects_datatype_mismatch() {
+        let t = tensor(vec![0u8; 8], DataType::Int64, vec![1]);
+        assert!(t.as_slice::<f32>().is_err());
+    }
+
+    #[test]
+    fn as_slice_rejects_byte_size_mismatch() {
+        // Shape says 2 elements, but only enough bytes for 1 `i32`.
+        let t = tensor(vec![0u8; 4], DataType::Int32, vec![2]);
+        assert!(t.as_slice::<i32>().is_err());
+    }
+
+    #[test]
+    fn as_slice_round_trips_little_endian() {
+        let values: Vec<i32> = vec![1, -2, 3];
+        let bytes = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+        let t = tensor(bytes, DataType::Int32, vec![values.len() as i64]);
+
+        assert_eq!(t.as_slice_with::<i32>(Endianness::Little).unwrap(), values);
+    }
+
+    #[test]
+    fn as_slice_round_trips_big_endian() {
+        let values: Vec<i32> = vec![1, -2, 3];
+        let bytes = values.iter().flat_map(|v| v.to_be_bytes()).collect();
+        let t = tensor(bytes, DataType::Int32, vec![values.len() as i64]);
+
+        assert_eq!(t.as_slice_with::<i32>(Endianness::Big).unwrap(), values);
+    }
+
+    #[test]
+    fn as_strings_decodes_every_element() {
+        let mut data = Vec::new();
+        data.extend_from_slice(&crate::encode_string("hello"));
+        data.extend_from_slice(&crate::encode_string("world"));
+        let t = tensor(data, DataType::Bytes, vec![2]);
+
+        assert_eq!(
+            t.as_strings().unwrap(),
+            vec!["hello".to_string(), "world".to_string()]
+        );
+    }
+}